@@ -6,10 +6,157 @@ use std::{
 pub struct LinkedNode<T> {
     pub item: Option<T>, // Option<> is used to facilitate pops (.take())
     next: Option<usize>,
+    prev: Option<usize>,
+    generation: u32,
+}
+
+// A stable, persistent key into a LinkedVector, valid for O(1) access via
+// `get`/`get_mut`. Unlike a physical index, a Handle cannot alias a different
+// element after the slot it named is freed and reused (ABA) — the generation
+// stored alongside the index is checked against the node's current one.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Handle {
+    index: usize,
+    generation: u32,
+}
+
+impl<T> Default for LinkedNode<T> {
+    fn default() -> Self {
+        LinkedNode {
+            item: None,
+            next: None,
+            prev: None,
+            generation: 0,
+        }
+    }
+}
+
+// The minimum size of the first segment; later segments double in size.
+const SEGMENT_MIN_LEN: usize = 8;
+
+// A chunked backing store: a `Vec` of power-of-two-sized, lazily-allocated
+// segments, indexed by decomposing a flat logical index into `(segment,
+// offset)`. Unlike a single `Vec<LinkedNode<T>>`, growing never relocates an
+// already-allocated segment, so every node's address stays pinned until
+// `compact` rebuilds the store, and large `T`s are never memcpy'd on growth.
+struct SegmentedStore<T> {
+    segments: Vec<Box<[LinkedNode<T>]>>,
+    len: usize,
+}
+
+impl<T> SegmentedStore<T> {
+    fn new() -> Self {
+        SegmentedStore {
+            segments: Vec::new(),
+            len: 0,
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn capacity(&self) -> usize {
+        self.segments.iter().map(|segment| segment.len()).sum()
+    }
+
+    // Bounds-checked access, used for Handle lookups where the index may
+    // come from a different (or since-shrunk) LinkedVector.
+    fn get(&self, index: usize) -> Option<&LinkedNode<T>> {
+        if index >= self.len {
+            return None;
+        }
+        let (segment, offset) = Self::locate(index);
+        self.segments.get(segment)?.get(offset)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut LinkedNode<T>> {
+        if index >= self.len {
+            return None;
+        }
+        let (segment, offset) = Self::locate(index);
+        self.segments.get_mut(segment)?.get_mut(offset)
+    }
+
+    // Decomposes a flat logical index into the segment that holds it and
+    // the offset within that segment.
+    fn locate(index: usize) -> (usize, usize) {
+        let mut segment = 0;
+        let mut segment_len = SEGMENT_MIN_LEN;
+        let mut base = 0;
+        loop {
+            if index < base + segment_len {
+                return (segment, index - base);
+            }
+            base += segment_len;
+            segment += 1;
+            segment_len *= 2;
+        }
+    }
+
+    fn push(&mut self, node: LinkedNode<T>) -> usize {
+        let index = self.len;
+        let (segment, offset) = Self::locate(index);
+
+        if segment == self.segments.len() {
+            let segment_len = SEGMENT_MIN_LEN << segment;
+            let fresh: Vec<LinkedNode<T>> =
+                (0..segment_len).map(|_| LinkedNode::default()).collect();
+            self.segments.push(fresh.into_boxed_slice());
+        }
+
+        self.segments[segment][offset] = node;
+        self.len += 1;
+        index
+    }
+
+    // Returns mutable references to the nodes at two distinct flat indices,
+    // splitting whichever level (the segment list, or a single segment) the
+    // two indices diverge at so both borrows can be live at once.
+    fn get_two_mut(&mut self, i: usize, j: usize) -> (&mut LinkedNode<T>, &mut LinkedNode<T>) {
+        debug_assert_ne!(i, j);
+        let (seg_i, off_i) = Self::locate(i);
+        let (seg_j, off_j) = Self::locate(j);
+
+        if seg_i == seg_j {
+            let segment = &mut self.segments[seg_i];
+            return if off_i < off_j {
+                let (left, right) = segment.split_at_mut(off_j);
+                (&mut left[off_i], &mut right[0])
+            } else {
+                let (left, right) = segment.split_at_mut(off_i);
+                (&mut right[0], &mut left[off_j])
+            };
+        }
+
+        if seg_i < seg_j {
+            let (left, right) = self.segments.split_at_mut(seg_j);
+            (&mut left[seg_i][off_i], &mut right[0][off_j])
+        } else {
+            let (left, right) = self.segments.split_at_mut(seg_i);
+            (&mut right[0][off_i], &mut left[seg_j][off_j])
+        }
+    }
+}
+
+impl<T> Index<usize> for SegmentedStore<T> {
+    type Output = LinkedNode<T>;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let (segment, offset) = Self::locate(index);
+        &self.segments[segment][offset]
+    }
+}
+
+impl<T> IndexMut<usize> for SegmentedStore<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let (segment, offset) = Self::locate(index);
+        &mut self.segments[segment][offset]
+    }
 }
 
 pub struct LinkedVector<T> {
-    data: Vec<LinkedNode<T>>,
+    data: SegmentedStore<T>,
     head: Option<usize>,
     tail: Option<usize>,
     freelist: Vec<usize>,
@@ -19,7 +166,7 @@ pub struct LinkedVector<T> {
 impl<T> LinkedVector<T> {
     pub fn new() -> LinkedVector<T> {
         LinkedVector {
-            data: Vec::new(),
+            data: SegmentedStore::new(),
             head: None,
             tail: None,
             freelist: Vec::new(),
@@ -47,48 +194,84 @@ impl<T> LinkedVector<T> {
     pub fn mem_used(&self) -> usize {
         // Gives an estimate of the total *heap* memory acitvely used, in bytes
         // Calculation formula:
-        //    data: (size_of(T) + usize) * data.len()
+        //    data: (size_of(T) + 2*usize + u32) * data.len()
         //    freelist: usize * freelist.len()
-        (size_of::<T>() + size_of::<usize>()) * self.data.len()
+        (size_of::<T>() + 2 * size_of::<usize>() + size_of::<u32>()) * self.data.len()
             + size_of::<usize>() * self.freelist.len()
     }
 
     pub fn true_mem_used(&self) -> usize {
         // Gives an estimate of the total *heap* memory allocated, in bytes
         // Calculation formula:
-        //    data: (size_of(T) + usize) * data.capacity()
+        //    data: (size_of(T) + 2*usize + u32) * data.capacity()
+        //      (data.capacity() sums every segment's length, since each
+        //      segment is allocated at its exact capacity up front)
         //    freelist: usize * freelist.capacity()
-        (size_of::<T>() + size_of::<usize>()) * self.data.capacity()
+        (size_of::<T>() + 2 * size_of::<usize>() + size_of::<u32>()) * self.data.capacity()
             + size_of::<usize>() * self.freelist.capacity()
     }
 
-    fn alloc(&mut self, new_node: LinkedNode<T>) -> usize {
+    // Allocates `new_node` into a free slot (if any) or a fresh one, carrying
+    // over whatever generation that slot is currently at so stale Handles
+    // pointing at it keep failing the check in `get`/`get_mut`.
+    fn alloc(&mut self, mut new_node: LinkedNode<T>) -> usize {
         match self.freelist.pop() {
             Some(idx) => {
+                new_node.generation = self.data[idx].generation;
                 self.data[idx] = new_node;
                 return idx;
             }
             None => {
-                self.data.push(new_node);
-                return self.data.len() - 1;
+                return self.data.push(new_node);
             }
         }
     }
 
-    pub fn push_front(&mut self, item: T) {
+    // Frees a physical slot, bumping its generation so outstanding Handles
+    // into it are invalidated immediately, even before the slot is reused.
+    fn free(&mut self, idx: usize) {
+        self.data[idx].generation = self.data[idx].generation.wrapping_add(1);
+        self.freelist.push(idx);
+    }
+
+    fn handle_of(&self, idx: usize) -> Handle {
+        Handle {
+            index: idx,
+            generation: self.data[idx].generation,
+        }
+    }
+
+    pub fn get(&self, handle: Handle) -> Option<&T> {
+        self.data
+            .get(handle.index)
+            .filter(|node| node.generation == handle.generation)
+            .and_then(|node| node.item.as_ref())
+    }
+
+    pub fn get_mut(&mut self, handle: Handle) -> Option<&mut T> {
+        self.data
+            .get_mut(handle.index)
+            .filter(|node| node.generation == handle.generation)
+            .and_then(|node| node.item.as_mut())
+    }
+
+    pub fn push_front(&mut self, item: T) -> Handle {
         let new_node = LinkedNode {
             item: Some(item),
             next: self.head,
+            prev: None,
+            generation: 0,
         };
         let nidx = self.alloc(new_node);
 
-        self.head = Some(nidx);
-        if self.tail == None {
-            // first element
-            self.tail = Some(nidx);
+        match self.head {
+            Some(old_head) => self.data[old_head].prev = Some(nidx),
+            None => self.tail = Some(nidx), // first element
         }
+        self.head = Some(nidx);
 
         self.length += 1;
+        self.handle_of(nidx)
     }
 
     pub fn head(&self) -> Option<&LinkedNode<T>> {
@@ -107,41 +290,51 @@ impl<T> LinkedVector<T> {
         self.tail.map(|idx| &mut self.data[idx])
     }
 
-    pub fn push_back(&mut self, item: T) {
+    pub fn push_back(&mut self, item: T) -> Handle {
         let nidx = self.alloc(LinkedNode {
             item: Some(item),
             next: None,
+            prev: self.tail,
+            generation: 0,
         });
 
-        match self.tail_mut() {
-            Some(tail_node) => {
-                tail_node.next = Some(nidx);
-                self.tail = Some(nidx);
-            }
-            None => {
-                // New item is both head and tail
-                self.head = Some(nidx);
-                self.tail = Some(nidx);
-            }
+        match self.tail {
+            Some(old_tail) => self.data[old_tail].next = Some(nidx),
+            None => self.head = Some(nidx), // new item is both head and tail
         }
+        self.tail = Some(nidx);
 
         self.length += 1;
+        self.handle_of(nidx)
     }
 
     pub fn pop_front(&mut self) -> Option<T> {
+        let oidx = self.head?;
+        self.head = self.data[oidx].next;
+
+        match self.head {
+            Some(new_head) => self.data[new_head].prev = None,
+            None => self.tail = None, // list is now empty
+        }
         self.length -= 1;
-        self.head.map(|oidx| {
-            self.head = self.data[oidx].next;
-            self.freelist.push(oidx);
-            self.data[oidx].item.take().unwrap()
-        })
+        let item = self.data[oidx].item.take();
+        self.free(oidx);
+        item
     }
 
-    // pop_back not supported since it's not an efficient operation.
-    //   To achieve that, ideally implement with a doubly linked list,
-    //   or just use delete() with len().
-    #[cfg(any())]
-    pub fn pop_back(&mut self) -> () {}
+    pub fn pop_back(&mut self) -> Option<T> {
+        let oidx = self.tail?;
+        self.tail = self.data[oidx].prev;
+
+        match self.tail {
+            Some(new_tail) => self.data[new_tail].next = None,
+            None => self.head = None, // list is now empty
+        }
+        self.length -= 1;
+        let item = self.data[oidx].item.take();
+        self.free(oidx);
+        item
+    }
 
     // Returns the physical index in data for an index.
     // Panics if out-of-bounds.
@@ -153,25 +346,363 @@ impl<T> LinkedVector<T> {
         current
     }
 
+    // Splices a node out of the list by its physical index in O(1),
+    // relinking its neighbours and freeing the slot.
+    fn remove_node(&mut self, phys: usize) -> T {
+        let prev = self.data[phys].prev;
+        let next = self.data[phys].next;
+
+        match prev {
+            Some(p) => self.data[p].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(n) => self.data[n].prev = prev,
+            None => self.tail = prev,
+        }
+
+        self.length -= 1;
+        let item = self.data[phys].item.take().unwrap();
+        self.free(phys);
+        item
+    }
+
     pub fn delete(&mut self, idx: usize) -> T {
         // Indexing will panic if idx out of bounds.
         // This debug assert is intended to panic earlier
         //   during debugs to improve clarity.
         debug_assert!(idx < self.length);
 
-        if idx == 0 {
-            return self.pop_front().unwrap();
+        let phys = self.physical_index_of(idx);
+        self.remove_node(phys)
+    }
+
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.head,
+            lv: self,
         }
+    }
 
-        let prev_phys = self.physical_index_of(idx - 1);
-        let remove_phys = self.data[prev_phys].next.expect("Index out of bounds");
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut {
+            current: self.tail,
+            lv: self,
+        }
+    }
 
-        self.data[prev_phys].next = self.data[remove_phys].next;
-        self.freelist.push(remove_phys);
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            data: &self.data,
+            next: self.head,
+        }
+    }
 
-        self.length -= 1;
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            data: &mut self.data,
+            next: self.head,
+        }
+    }
 
-        self.data[idx].item.take().unwrap()
+    // Trims the spare capacity of the segment table and of `freelist`
+    // without touching any nodes. Unlike `compact`, physical indices and
+    // Handles remain valid. Each segment is a `Box<[_]>` allocated at its
+    // exact size, so unused slots in the last segment are not released.
+    pub fn shrink_to_fit(&mut self) {
+        self.data.segments.shrink_to_fit();
+        self.freelist.shrink_to_fit();
+    }
+
+    // Rebuilds `data` in logical (head-to-tail) order, dropping every freed
+    // slot so the new segments no longer carry deletion holes. This
+    // reclaims the gap between `len`/`mem_used` and `true_len`/
+    // `true_mem_used` that churn otherwise leaks for the lifetime of the
+    // structure, down to at most one partially-filled trailing segment.
+    //
+    // Every node's physical index changes, so this bumps the generation of
+    // each surviving node: any Handle obtained before the call is invalidated
+    // just like it would be by a deletion.
+    pub fn compact(&mut self) {
+        let mut new_data: SegmentedStore<T> = SegmentedStore::new();
+
+        let mut idx = self.head;
+        while let Some(old_idx) = idx {
+            let node = &mut self.data[old_idx];
+            idx = node.next;
+
+            let new_idx = new_data.len();
+            new_data.push(LinkedNode {
+                item: node.item.take(),
+                next: None,
+                prev: if new_idx == 0 {
+                    None
+                } else {
+                    Some(new_idx - 1)
+                },
+                generation: node.generation.wrapping_add(1),
+            });
+            if new_idx > 0 {
+                new_data[new_idx - 1].next = Some(new_idx);
+            }
+        }
+
+        let new_len = new_data.len();
+        self.head = if new_len == 0 { None } else { Some(0) };
+        self.tail = if new_len == 0 {
+            None
+        } else {
+            Some(new_len - 1)
+        };
+        self.data = new_data;
+        self.freelist.clear();
+        self.shrink_to_fit();
+    }
+
+    // Returns mutable references to the items at two distinct physical
+    // indices, via `split_at_mut` so both borrows are live at once.
+    fn two_mut(&mut self, i: usize, j: usize) -> (&mut T, &mut T) {
+        debug_assert_ne!(i, j);
+        let (a, b) = self.data.get_two_mut(i, j);
+        (a.item.as_mut().unwrap(), b.item.as_mut().unwrap())
+    }
+
+    // Keeps only the elements for which `f` returns true, walking `next`
+    // once and splicing rejected nodes straight into `freelist` in O(n)
+    // with no data movement.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let mut idx = self.head;
+        while let Some(current) = idx {
+            idx = self.data[current].next;
+            let keep = f(self.data[current].item.as_ref().unwrap());
+            if !keep {
+                self.remove_node(current);
+            }
+        }
+    }
+
+    // Removes consecutive elements for which `same_bucket(a, b)` returns
+    // true, keeping the first (`b`) of each equal run.
+    //
+    // A first pass over the list just advances `prev`/`cur` and calls
+    // `same_bucket`, touching nothing until the first duplicate is found;
+    // only then does it start splicing `cur` out of the list (`prev.next =
+    // cur.next`, freeing `cur`). So the common all-unique case never
+    // relinks anything, and because only adjacent elements are ever
+    // compared, partially-sorted or grouped data dedups in a single pass.
+    pub fn dedup_by<F>(&mut self, mut same_bucket: F)
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        let Some(mut prev) = self.head else {
+            return;
+        };
+        let mut cur = self.data[prev].next;
+
+        while let Some(cur_idx) = cur {
+            cur = self.data[cur_idx].next;
+
+            let is_dup = {
+                let (cur_item, prev_item) = self.two_mut(cur_idx, prev);
+                same_bucket(cur_item, prev_item)
+            };
+
+            if is_dup {
+                self.remove_node(cur_idx);
+            } else {
+                prev = cur_idx;
+            }
+        }
+    }
+}
+
+impl<T: PartialEq> LinkedVector<T> {
+    // Removes consecutive equal elements, keeping the first of each run.
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b)
+    }
+}
+
+// A cursor over a LinkedVector that can walk and splice in O(1),
+// holding the physical index of its current node instead of a logical position.
+pub struct CursorMut<'a, T> {
+    lv: &'a mut LinkedVector<T>,
+    current: Option<usize>,
+}
+
+impl<'a, T> CursorMut<'a, T> {
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.current.and_then(|idx| self.lv.data[idx].item.as_mut())
+    }
+
+    pub fn move_next(&mut self) {
+        if let Some(idx) = self.current {
+            self.current = self.lv.data[idx].next;
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        if let Some(idx) = self.current {
+            self.current = self.lv.data[idx].prev;
+        }
+    }
+
+    // Inserts `item` immediately before the current node, in O(1).
+    // If the cursor has no current node (the list is empty, or the cursor
+    // has moved past either end), pushes `item` onto the back.
+    pub fn insert_before(&mut self, item: T) -> Handle {
+        let idx = match self.current {
+            Some(idx) => idx,
+            None => return self.lv.push_back(item),
+        };
+
+        let prev = self.lv.data[idx].prev;
+        let nidx = self.lv.alloc(LinkedNode {
+            item: Some(item),
+            next: Some(idx),
+            prev,
+            generation: 0,
+        });
+        self.lv.data[idx].prev = Some(nidx);
+
+        match prev {
+            Some(p) => self.lv.data[p].next = Some(nidx),
+            None => self.lv.head = Some(nidx),
+        }
+
+        self.lv.length += 1;
+        self.lv.handle_of(nidx)
+    }
+
+    // Inserts `item` immediately after the current node, in O(1).
+    // If the cursor has no current node (the list is empty, or the cursor
+    // has moved past either end), pushes `item` onto the back.
+    pub fn insert_after(&mut self, item: T) -> Handle {
+        let idx = match self.current {
+            Some(idx) => idx,
+            None => return self.lv.push_back(item),
+        };
+
+        let next = self.lv.data[idx].next;
+        let nidx = self.lv.alloc(LinkedNode {
+            item: Some(item),
+            next,
+            prev: Some(idx),
+            generation: 0,
+        });
+        self.lv.data[idx].next = Some(nidx);
+
+        match next {
+            Some(n) => self.lv.data[n].prev = Some(nidx),
+            None => self.lv.tail = Some(nidx),
+        }
+
+        self.lv.length += 1;
+        self.lv.handle_of(nidx)
+    }
+
+    // Removes the current node and advances the cursor onto what followed it, in O(1).
+    pub fn remove_current(&mut self) -> Option<T> {
+        let idx = self.current?;
+        let next = self.lv.data[idx].next;
+        let item = self.lv.remove_node(idx);
+        self.current = next;
+        Some(item)
+    }
+}
+
+// Follows `next` pointers once, so logical order is preserved
+// regardless of how scattered the physical slots are after deletions.
+pub struct Iter<'a, T> {
+    data: &'a SegmentedStore<T>,
+    next: Option<usize>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.next?;
+        self.next = self.data[idx].next;
+        self.data[idx].item.as_ref()
+    }
+}
+
+pub struct IterMut<'a, T> {
+    data: &'a mut SegmentedStore<T>,
+    next: Option<usize>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let idx = self.next?;
+        self.next = self.data[idx].next;
+
+        // SAFETY: each node is visited at most once (the `next` chain has no
+        // cycles), so the mutable borrows handed out never alias.
+        let node = unsafe { &mut *(&mut self.data[idx] as *mut LinkedNode<T>) };
+        node.item.as_mut()
+    }
+}
+
+// Draining iterator used by `IntoIterator for LinkedVector<T>`.
+pub struct IntoIter<T> {
+    lv: LinkedVector<T>,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lv.pop_front()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a LinkedVector<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut LinkedVector<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T> IntoIterator for LinkedVector<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { lv: self }
+    }
+}
+
+impl<T> FromIterator<T> for LinkedVector<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut lv = LinkedVector::new();
+        lv.extend(iter);
+        lv
+    }
+}
+
+impl<T> Extend<T> for LinkedVector<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push_back(item);
+        }
     }
 }
 
@@ -270,4 +801,181 @@ mod tests {
 
         my_vec.delete(1); // Should panic here
     }
+
+    #[test]
+    fn pop_back_mirrors_pop_front() {
+        let mut my_vec = LinkedVector::<u64>::new();
+        assert_eq!(my_vec.pop_back(), None);
+
+        my_vec.push_back(1u64);
+        my_vec.push_back(2u64);
+        my_vec.push_back(3u64);
+
+        assert_eq!(my_vec.pop_back(), Some(3));
+        assert_eq!(format!("{my_vec:?}").trim(), "1 2");
+
+        assert_eq!(my_vec.pop_back(), Some(2));
+        assert_eq!(my_vec.pop_back(), Some(1));
+        assert_eq!(my_vec.pop_back(), None);
+        assert_eq!(my_vec.len(), 0);
+
+        // List should still be usable after being emptied out from the back.
+        my_vec.push_back(9u64);
+        assert_eq!(format!("{my_vec:?}").trim(), "9");
+    }
+
+    #[test]
+    fn cursor_mut_walks_and_splices() {
+        let mut my_vec = LinkedVector::<u64>::new();
+        my_vec.push_back(1u64);
+        my_vec.push_back(2u64);
+        my_vec.push_back(3u64);
+
+        let mut cursor = my_vec.cursor_front_mut();
+        assert_eq!(cursor.current(), Some(&mut 1));
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 2));
+
+        cursor.insert_before(10u64);
+        cursor.insert_after(20u64);
+        assert_eq!(format!("{my_vec:?}").trim(), "1 10 2 20 3");
+
+        let mut cursor = my_vec.cursor_back_mut();
+        assert_eq!(cursor.current(), Some(&mut 3));
+        let removed = cursor.remove_current();
+        assert_eq!(removed, Some(3));
+        assert_eq!(cursor.current(), None);
+        assert_eq!(format!("{my_vec:?}").trim(), "1 10 2 20");
+
+        assert_eq!(my_vec.len(), 4);
+    }
+
+    #[test]
+    fn iter_follows_logical_order_after_deletions() {
+        let mut my_vec = LinkedVector::<u64>::new();
+        my_vec.push_back(1u64);
+        my_vec.push_back(2u64);
+        my_vec.push_back(3u64);
+        my_vec.push_back(4u64);
+
+        // Scatter the physical slots so a naive index scan would get this wrong.
+        my_vec.delete(1); // removes 2
+        my_vec.push_back(5u64);
+
+        assert_eq!(my_vec.iter().copied().collect::<Vec<_>>(), vec![1, 3, 4, 5]);
+
+        for item in my_vec.iter_mut() {
+            *item *= 10;
+        }
+        assert_eq!(
+            my_vec.iter().copied().collect::<Vec<_>>(),
+            vec![10, 30, 40, 50]
+        );
+
+        assert_eq!(my_vec.into_iter().collect::<Vec<_>>(), vec![10, 30, 40, 50]);
+    }
+
+    #[test]
+    fn segmented_store_spans_many_segment_boundaries() {
+        // SEGMENT_MIN_LEN is 8, doubling each segment, so 200 pushes walks
+        // across several segment boundaries (8, 24, 56, 120, ...).
+        let my_vec: LinkedVector<usize> = (0..200).collect();
+        assert_eq!(my_vec.len(), 200);
+        assert_eq!(my_vec.true_len(), 200);
+        assert_eq!(
+            my_vec.iter().copied().collect::<Vec<_>>(),
+            (0..200).collect::<Vec<_>>()
+        );
+
+        // Logical indices near a segment boundary must still resolve correctly.
+        assert_eq!(my_vec[7].item, Some(7));
+        assert_eq!(my_vec[8].item, Some(8));
+        assert_eq!(my_vec[199].item, Some(199));
+    }
+
+    #[test]
+    fn retain_keeps_logical_order() {
+        let mut my_vec: LinkedVector<u64> = (1..=6).collect();
+        my_vec.retain(|&x| x % 2 == 0);
+        assert_eq!(format!("{my_vec:?}").trim(), "2 4 6");
+        assert_eq!(my_vec.len(), 3);
+
+        my_vec.retain(|_| false);
+        assert_eq!(format!("{my_vec:?}").trim(), "");
+        assert_eq!(my_vec.len(), 0);
+
+        my_vec.push_back(9u64);
+        assert_eq!(format!("{my_vec:?}").trim(), "9");
+    }
+
+    #[test]
+    fn dedup_collapses_consecutive_runs_only() {
+        let mut my_vec: LinkedVector<u64> = [1, 1, 2, 3, 3, 3, 1]
+            .into_iter()
+            .collect::<LinkedVector<_>>();
+        my_vec.dedup();
+        assert_eq!(format!("{my_vec:?}").trim(), "1 2 3 1");
+        assert_eq!(my_vec.len(), 4);
+
+        // Already-unique input should be left completely untouched.
+        let mut unique: LinkedVector<u64> = (1..=4).collect();
+        unique.dedup();
+        assert_eq!(format!("{unique:?}").trim(), "1 2 3 4");
+    }
+
+    #[test]
+    fn compact_reclaims_holes_and_invalidates_handles() {
+        let mut my_vec = LinkedVector::<u64>::new();
+        my_vec.push_back(1u64);
+        let stale = my_vec.push_back(2u64);
+        my_vec.push_back(3u64);
+        my_vec.push_back(4u64);
+
+        my_vec.delete(1); // removes 2, leaving a hole in `data`
+        assert_eq!(my_vec.len(), 3);
+        assert_eq!(my_vec.true_len(), 4);
+
+        my_vec.compact();
+        assert_eq!(my_vec.len(), 3);
+        assert_eq!(my_vec.true_len(), 3); // the hole is gone
+        assert_eq!(format!("{my_vec:?}").trim(), "1 3 4");
+
+        // The handle into the pre-compaction layout must no longer resolve.
+        assert_eq!(my_vec.get(stale), None);
+
+        // The structure stays fully usable after compaction.
+        my_vec.push_back(5u64);
+        assert_eq!(format!("{my_vec:?}").trim(), "1 3 4 5");
+    }
+
+    #[test]
+    fn handles_detect_stale_aba() {
+        let mut my_vec = LinkedVector::<u64>::new();
+        let a = my_vec.push_back(1u64);
+        let b = my_vec.push_back(2u64);
+
+        assert_eq!(my_vec.get(a), Some(&1));
+        assert_eq!(my_vec.get(b), Some(&2));
+
+        my_vec.delete(0); // frees a's slot
+        assert_eq!(my_vec.get(a), None);
+        assert_eq!(my_vec.get(b), Some(&2));
+
+        // Reuse a's freed slot; the new handle must not alias the old one.
+        let c = my_vec.push_front(3u64);
+        assert_eq!(my_vec.get(a), None);
+        assert_eq!(my_vec.get(c), Some(&3));
+
+        *my_vec.get_mut(c).unwrap() += 1;
+        assert_eq!(my_vec.get(c), Some(&4));
+    }
+
+    #[test]
+    fn from_iter_and_extend_build_in_order() {
+        let mut my_vec: LinkedVector<u64> = (1..=3).collect();
+        assert_eq!(format!("{my_vec:?}").trim(), "1 2 3");
+
+        my_vec.extend([4u64, 5u64]);
+        assert_eq!(format!("{my_vec:?}").trim(), "1 2 3 4 5");
+    }
 }